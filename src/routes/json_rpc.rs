@@ -1,5 +1,6 @@
 use actix_web::{web, HttpResponse};
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use unicode_segmentation::UnicodeSegmentation;
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -9,7 +10,8 @@ pub struct CharCountParams {
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct CharCountRequest {
-    id: String,
+    #[serde(default)]
+    id: Option<String>,
     jsonrpc: String,
     method: String,
     params: CharCountParams,
@@ -40,30 +42,85 @@ pub struct MethodNotFoundErrorResponse {
     jsonrpc: String,
 }
 
-pub async fn json_rpc_handler(item: web::Json<CharCountRequest>) -> HttpResponse {
-    match item.method.as_str() {
+#[derive(Debug, Serialize)]
+pub struct InvalidRequestError {
+    code: i32,
+    message: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct InvalidRequestErrorResponse {
+    error: InvalidRequestError,
+    id: Option<String>,
+    jsonrpc: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum JsonRpcRequest {
+    Single(CharCountRequest),
+    Batch(Vec<CharCountRequest>),
+}
+
+/// Dispatches a single call through the existing method table. Returns `None`
+/// for notifications (no `id`), which per the JSON-RPC 2.0 spec never get a
+/// response.
+fn dispatch(request: &CharCountRequest) -> Option<Value> {
+    let id = request.id.clone()?;
+
+    let response = match request.method.as_str() {
         "char_count" => {
-            let some_string = item.params.some_string.trim();
+            let some_string = request.params.some_string.trim();
             let count = some_string.graphemes(true).count() as i32;
-            let response = CharCountResponse {
-                id: item.id.clone(),
-                jsonrpc: item.jsonrpc.clone(),
-                result: CharCountResult { count },
-            };
 
-            HttpResponse::Ok().json(response)
+            serde_json::to_value(CharCountResponse {
+                id,
+                jsonrpc: request.jsonrpc.clone(),
+                result: CharCountResult { count },
+            })
         }
-        _ => {
-            let response = MethodNotFoundErrorResponse {
-                error: MethodNotFoundError {
-                    code: -32601,
-                    message: "Method not found".to_string(),
-                },
-                id: item.id.clone(),
-                jsonrpc: item.jsonrpc.clone(),
-            };
+        _ => serde_json::to_value(MethodNotFoundErrorResponse {
+            error: MethodNotFoundError {
+                code: -32601,
+                message: "Method not found".to_string(),
+            },
+            id,
+            jsonrpc: request.jsonrpc.clone(),
+        }),
+    };
+
+    response.ok()
+}
+
+fn invalid_request() -> HttpResponse {
+    HttpResponse::Ok().json(InvalidRequestErrorResponse {
+        error: InvalidRequestError {
+            code: -32600,
+            message: "Invalid Request".to_string(),
+        },
+        id: None,
+        jsonrpc: "2.0".to_string(),
+    })
+}
+
+pub async fn json_rpc_handler(item: web::Json<JsonRpcRequest>) -> HttpResponse {
+    match item.into_inner() {
+        JsonRpcRequest::Single(request) => match dispatch(&request) {
+            Some(response) => HttpResponse::Ok().json(response),
+            None => HttpResponse::Ok().finish(),
+        },
+        JsonRpcRequest::Batch(requests) => {
+            if requests.is_empty() {
+                return invalid_request();
+            }
+
+            let responses: Vec<Value> = requests.iter().filter_map(dispatch).collect();
 
-            HttpResponse::Ok().json(response)
+            if responses.is_empty() {
+                return HttpResponse::Ok().finish();
+            }
+
+            HttpResponse::Ok().json(responses)
         }
     }
 }
@@ -87,7 +144,7 @@ mod tests {
             let req = test::TestRequest::post()
                 .uri("/")
                 .set_json(CharCountRequest {
-                    id: "00000000-0000-0000-0000-000000000000".to_owned(),
+                    id: Some("00000000-0000-0000-0000-000000000000".to_owned()),
                     jsonrpc: "2.0".to_owned(),
                     method: "char_count".to_owned(),
                     params: CharCountParams {
@@ -131,7 +188,7 @@ mod tests {
             let req = test::TestRequest::post()
                 .uri("/")
                 .set_json(CharCountRequest {
-                    id: "00000000-0000-0000-0000-000000000000".to_owned(),
+                    id: Some("00000000-0000-0000-0000-000000000000".to_owned()),
                     jsonrpc: "2.0".to_owned(),
                     method: "char_count".to_owned(),
                     params: CharCountParams {
@@ -168,7 +225,7 @@ mod tests {
         let req = test::TestRequest::post()
             .uri("/")
             .set_json(&CharCountRequest {
-                id: "00000000-0000-0000-0000-000000000000".to_owned(),
+                id: Some("00000000-0000-0000-0000-000000000000".to_owned()),
                 jsonrpc: "2.0".to_owned(),
                 method: "wrong".to_owned(),
                 params: CharCountParams {
@@ -186,4 +243,140 @@ mod tests {
             r##"{"error":{"code":-32601,"message":"Method not found"},"id":"00000000-0000-0000-0000-000000000000","jsonrpc":"2.0"}"##
         );
     }
+
+    #[actix_web::test]
+    async fn test_batch_request_preserves_order() {
+        let app = test::init_service(
+            App::new().service(web::resource("/").route(web::post().to(json_rpc_handler))),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri("/")
+            .set_json(vec![
+                CharCountRequest {
+                    id: Some("1".to_owned()),
+                    jsonrpc: "2.0".to_owned(),
+                    method: "char_count".to_owned(),
+                    params: CharCountParams {
+                        some_string: "Oliver".to_owned(),
+                    },
+                },
+                CharCountRequest {
+                    id: Some("2".to_owned()),
+                    jsonrpc: "2.0".to_owned(),
+                    method: "char_count".to_owned(),
+                    params: CharCountParams {
+                        some_string: "Hi".to_owned(),
+                    },
+                },
+            ])
+            .to_request();
+        let resp = app.call(req).await.unwrap();
+
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let body_bytes = to_bytes(resp.into_body()).await.unwrap();
+        assert_eq!(
+            body_bytes,
+            r##"[{"id":"1","jsonrpc":"2.0","result":{"count":6}},{"id":"2","jsonrpc":"2.0","result":{"count":2}}]"##
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_batch_request_omits_notifications() {
+        let app = test::init_service(
+            App::new().service(web::resource("/").route(web::post().to(json_rpc_handler))),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri("/")
+            .set_json(vec![
+                CharCountRequest {
+                    id: None,
+                    jsonrpc: "2.0".to_owned(),
+                    method: "char_count".to_owned(),
+                    params: CharCountParams {
+                        some_string: "Oliver".to_owned(),
+                    },
+                },
+                CharCountRequest {
+                    id: Some("1".to_owned()),
+                    jsonrpc: "2.0".to_owned(),
+                    method: "char_count".to_owned(),
+                    params: CharCountParams {
+                        some_string: "Hi".to_owned(),
+                    },
+                },
+            ])
+            .to_request();
+        let resp = app.call(req).await.unwrap();
+
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let body_bytes = to_bytes(resp.into_body()).await.unwrap();
+        assert_eq!(
+            body_bytes,
+            r##"[{"id":"1","jsonrpc":"2.0","result":{"count":2}}]"##
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_batch_of_only_notifications_returns_empty_body() {
+        let app = test::init_service(
+            App::new().service(web::resource("/").route(web::post().to(json_rpc_handler))),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri("/")
+            .set_json(vec![
+                CharCountRequest {
+                    id: None,
+                    jsonrpc: "2.0".to_owned(),
+                    method: "char_count".to_owned(),
+                    params: CharCountParams {
+                        some_string: "Oliver".to_owned(),
+                    },
+                },
+                CharCountRequest {
+                    id: None,
+                    jsonrpc: "2.0".to_owned(),
+                    method: "char_count".to_owned(),
+                    params: CharCountParams {
+                        some_string: "Hi".to_owned(),
+                    },
+                },
+            ])
+            .to_request();
+        let resp = app.call(req).await.unwrap();
+
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let body_bytes = to_bytes(resp.into_body()).await.unwrap();
+        assert_eq!(body_bytes, "");
+    }
+
+    #[actix_web::test]
+    async fn test_empty_batch_is_invalid_request() {
+        let app = test::init_service(
+            App::new().service(web::resource("/").route(web::post().to(json_rpc_handler))),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri("/")
+            .set_json(Vec::<CharCountRequest>::new())
+            .to_request();
+        let resp = app.call(req).await.unwrap();
+
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let body_bytes = to_bytes(resp.into_body()).await.unwrap();
+        assert_eq!(
+            body_bytes,
+            r##"{"error":{"code":-32600,"message":"Invalid Request"},"id":null,"jsonrpc":"2.0"}"##
+        );
+    }
 }